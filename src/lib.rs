@@ -0,0 +1,777 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub id: String,
+    pub name: String,
+    pub coordinates: (f64, f64),
+}
+
+/// Entry stored in the [`NavigationGraph`] spatial index: just enough to find candidate
+/// location ids by coordinate, with the exact haversine check left to the caller.
+#[derive(Debug, Clone)]
+struct IndexedLocation {
+    id: String,
+    coordinates: (f64, f64),
+}
+
+impl RTreeObject for IndexedLocation {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point([self.coordinates.0, self.coordinates.1])
+    }
+}
+
+impl PointDistance for IndexedLocation {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let dlat = self.coordinates.0 - point[0];
+        let dlon = self.coordinates.1 - point[1];
+        dlat * dlat + dlon * dlon
+    }
+}
+
+#[derive(Debug)]
+struct Connection {
+    to: String,
+    distance: f64,
+}
+
+/// CSV row shape for locations: `id,name,lat,lon`. Converted into a [`Location`] on load.
+#[derive(Debug, Deserialize)]
+struct LocationRecord {
+    id: String,
+    name: String,
+    lat: f64,
+    lon: f64,
+}
+
+impl From<LocationRecord> for Location {
+    fn from(record: LocationRecord) -> Self {
+        Location {
+            id: record.id,
+            name: record.name,
+            coordinates: (record.lat, record.lon),
+        }
+    }
+}
+
+/// CSV row shape for connections: `from,to,distance`.
+#[derive(Debug, Deserialize)]
+struct ConnectionRecord {
+    from: String,
+    to: String,
+    distance: f64,
+}
+
+/// Errors produced while loading a graph from CSV.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(std::io::Error),
+    Csv(csv::Error),
+    UnknownLocation(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "I/O error loading graph: {err}"),
+            LoadError::Csv(err) => write!(f, "malformed CSV row: {err}"),
+            LoadError::UnknownLocation(id) => {
+                write!(f, "connection references unknown location id: {id}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<std::io::Error> for LoadError {
+    fn from(err: std::io::Error) -> Self {
+        LoadError::Io(err)
+    }
+}
+
+impl From<csv::Error> for LoadError {
+    fn from(err: csv::Error) -> Self {
+        LoadError::Csv(err)
+    }
+}
+
+#[derive(Debug)]
+pub struct NavigationGraph {
+    locations: HashMap<String, Location>,
+    connections: HashMap<String, Vec<Connection>>,
+    spatial_index: Option<RTree<IndexedLocation>>,
+}
+
+/// Wraps `f64` so it can sit in a `BinaryHeap`, which requires `Ord`.
+///
+/// Navigation distances are never expected to be `NaN`; encountering one
+/// indicates malformed input data, so comparisons panic rather than
+/// silently ordering NaNs somewhere arbitrary.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NonNan(f64);
+
+impl Eq for NonNan {}
+
+impl PartialOrd for NonNan {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NonNan {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .partial_cmp(&other.0)
+            .expect("distance comparison encountered NaN")
+    }
+}
+
+/// Strategy used by [`NavigationGraph::shortest_path_with_mode`] to order the search frontier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Expand by accumulated distance `g`. Always optimal.
+    Dijkstra,
+    /// Expand by `g + h`, where `h` is the haversine distance to the goal in meters. Optimal
+    /// when connection distances are in meters and no shorter than the great-circle distance
+    /// between their endpoints (then `h` is admissible), and usually explores far fewer nodes
+    /// than plain Dijkstra.
+    AStar,
+    /// Expand by `h` alone, ignoring `g`. Fast, but the returned path is not guaranteed optimal.
+    Greedy,
+}
+
+impl Default for NavigationGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NavigationGraph {
+    pub fn new() -> Self {
+        NavigationGraph {
+            locations: HashMap::new(),
+            connections: HashMap::new(),
+            spatial_index: None,
+        }
+    }
+
+    pub fn add_location(&mut self, location: Location) {
+        self.locations.insert(location.id.clone(), location);
+    }
+
+    pub fn add_connection(&mut self, from: String, to: String, distance: f64) {
+        let connection = Connection { to, distance };
+        self.connections.entry(from).or_default().push(connection);
+    }
+
+    pub fn get_location(&self, id: &str) -> Option<&Location> {
+        self.locations.get(id)
+    }
+
+    /// Builds a graph from a locations CSV (`id,name,lat,lon`) and a connections CSV
+    /// (`from,to,distance`). Fails on malformed rows or connections referencing a
+    /// location id that wasn't loaded.
+    pub fn from_csv(
+        locations_path: impl AsRef<Path>,
+        connections_path: impl AsRef<Path>,
+    ) -> Result<Self, LoadError> {
+        let mut graph = NavigationGraph::new();
+
+        let mut locations_reader = csv::Reader::from_path(locations_path)?;
+        for result in locations_reader.deserialize() {
+            let record: LocationRecord = result?;
+            graph.add_location(record.into());
+        }
+
+        let mut connections_reader = csv::Reader::from_path(connections_path)?;
+        for result in connections_reader.deserialize() {
+            let record: ConnectionRecord = result?;
+            if !graph.locations.contains_key(&record.from) {
+                return Err(LoadError::UnknownLocation(record.from));
+            }
+            if !graph.locations.contains_key(&record.to) {
+                return Err(LoadError::UnknownLocation(record.to));
+            }
+            graph.add_connection(record.from, record.to, record.distance);
+        }
+
+        Ok(graph)
+    }
+
+    pub fn shortest_path(&self, start: &str, end: &str) -> Option<(Vec<String>, f64)> {
+        self.shortest_path_with_mode(start, end, SearchMode::Dijkstra)
+    }
+
+    /// Finds a path from `start` to `end` using the given [`SearchMode`].
+    ///
+    /// `Dijkstra` always returns the optimal (minimum-distance) path, and so does `AStar` when
+    /// its heuristic is admissible (see [`SearchMode::AStar`]); `Greedy` returns whatever path
+    /// reaches `end` first, which may not be optimal.
+    pub fn shortest_path_with_mode(
+        &self,
+        start: &str,
+        end: &str,
+        mode: SearchMode,
+    ) -> Option<(Vec<String>, f64)> {
+        self.dijkstra_core(start, end, mode, |_| true, |connection| connection.distance)
+    }
+
+    /// Like [`shortest_path`](Self::shortest_path), but only traverses connections whose
+    /// `distance` is at most `max_hop` — for vehicles/aircraft that cannot cover an
+    /// arbitrarily long single leg. Returns `None` if no such path exists.
+    pub fn shortest_path_within_range(
+        &self,
+        start: &str,
+        end: &str,
+        max_hop: f64,
+    ) -> Option<(Vec<String>, f64)> {
+        self.dijkstra_core(
+            start,
+            end,
+            SearchMode::Dijkstra,
+            |connection| connection.distance <= max_hop,
+            |connection| connection.distance,
+        )
+    }
+
+    /// Like [`shortest_path_within_range`](Self::shortest_path_within_range), but minimizes the
+    /// number of hops instead of total distance — "fewest refuel stops" rather than
+    /// "shortest total distance". The returned `f64` is the hop count.
+    pub fn fewest_hops_within_range(
+        &self,
+        start: &str,
+        end: &str,
+        max_hop: f64,
+    ) -> Option<(Vec<String>, f64)> {
+        self.dijkstra_core(
+            start,
+            end,
+            SearchMode::Dijkstra,
+            |connection| connection.distance <= max_hop,
+            |_connection| 1.0,
+        )
+    }
+
+    /// Shared Dijkstra/A*/Greedy search core. `edge_allowed` filters out connections that
+    /// cannot be traversed (e.g. exceeding a max hop distance); `edge_cost` determines what
+    /// is being minimized (e.g. distance, or a flat `1.0` per hop).
+    fn dijkstra_core(
+        &self,
+        start: &str,
+        end: &str,
+        mode: SearchMode,
+        edge_allowed: impl Fn(&Connection) -> bool,
+        edge_cost: impl Fn(&Connection) -> f64,
+    ) -> Option<(Vec<String>, f64)> {
+        let heuristic = |node: &str| -> f64 {
+            if mode == SearchMode::Dijkstra {
+                return 0.0;
+            }
+            match (self.locations.get(node), self.locations.get(end)) {
+                (Some(a), Some(b)) => haversine(a.coordinates, b.coordinates),
+                _ => 0.0,
+            }
+        };
+
+        let mut distances: HashMap<String, f64> = HashMap::new();
+        let mut previous: HashMap<String, String> = HashMap::new();
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut heap: BinaryHeap<(Reverse<NonNan>, String)> = BinaryHeap::new();
+
+        distances.insert(start.to_string(), 0.0);
+        heap.push((Reverse(NonNan(heuristic(start))), start.to_string()));
+
+        while let Some((_, current)) = heap.pop() {
+            if current == end {
+                let mut path = Vec::new();
+                let mut current_node = end.to_string();
+                path.push(current_node.clone());
+
+                while let Some(prev) = previous.get(&current_node) {
+                    path.push(prev.clone());
+                    current_node = prev.clone();
+                }
+
+                path.reverse();
+                return Some((path, *distances.get(end).unwrap()));
+            }
+
+            if !visited.insert(current.clone()) {
+                continue;
+            }
+
+            let g = *distances.get(&current).unwrap_or(&f64::INFINITY);
+
+            if let Some(connections) = self.connections.get(&current) {
+                for connection in connections {
+                    if !edge_allowed(connection) {
+                        continue;
+                    }
+
+                    let new_distance = g + edge_cost(connection);
+                    let is_shorter = distances
+                        .get(&connection.to)
+                        .is_none_or(|&d| new_distance < d);
+
+                    if is_shorter {
+                        distances.insert(connection.to.clone(), new_distance);
+                        previous.insert(connection.to.clone(), current.clone());
+                        let priority = match mode {
+                            SearchMode::Greedy => heuristic(&connection.to),
+                            SearchMode::Dijkstra | SearchMode::AStar => {
+                                new_distance + heuristic(&connection.to)
+                            }
+                        };
+                        heap.push((Reverse(NonNan(priority)), connection.to.clone()));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Builds (or rebuilds) the spatial index used by [`nearby_locations`](Self::nearby_locations)
+    /// to avoid scanning every location. Call again after adding locations to pick them up.
+    pub fn build_spatial_index(&mut self) {
+        let entries = self
+            .locations
+            .values()
+            .map(|loc| IndexedLocation {
+                id: loc.id.clone(),
+                coordinates: loc.coordinates,
+            })
+            .collect();
+        self.spatial_index = Some(RTree::bulk_load(entries));
+    }
+
+    /// Returns locations within `radius` meters of `center`, using great-circle distance.
+    ///
+    /// Uses the spatial index built by [`build_spatial_index`](Self::build_spatial_index)
+    /// when one is present, falling back to a linear scan otherwise.
+    pub fn nearby_locations(&self, center: &str, radius: f64) -> Vec<&Location> {
+        let center_loc = match self.locations.get(center) {
+            Some(loc) => loc,
+            None => return Vec::new(),
+        };
+
+        match &self.spatial_index {
+            Some(index) => self.nearby_locations_indexed(index, center_loc, radius),
+            None => self.nearby_locations_scan(center_loc, radius),
+        }
+    }
+
+    fn nearby_locations_scan(&self, center_loc: &Location, radius: f64) -> Vec<&Location> {
+        let mut result = Vec::new();
+        for loc in self.locations.values() {
+            if loc.id != center_loc.id {
+                let distance = haversine(loc.coordinates, center_loc.coordinates);
+                if distance <= radius {
+                    result.push(loc);
+                }
+            }
+        }
+        result
+    }
+
+    /// Gathers candidates within the bounding box implied by `radius` via the R-tree, then
+    /// refines with the exact haversine distance (the index works in raw degrees, so its
+    /// bounding box is only an approximation of the meter-based `radius`).
+    fn nearby_locations_indexed(
+        &self,
+        index: &RTree<IndexedLocation>,
+        center_loc: &Location,
+        radius: f64,
+    ) -> Vec<&Location> {
+        // Degrees are converted on the same sphere `haversine` measures on. Longitude degrees
+        // shrink towards the poles by a factor of cos(lat), and every point within `radius`
+        // lies no further poleward than `max_lat`. Scaling by the longitude figure at `max_lat`
+        // yields the larger (more conservative) degree radius, keeping the R-tree candidate
+        // circle a superset of the true circle in both directions.
+        let meters_per_degree = EARTH_RAD.to_radians();
+        let max_lat = (center_loc.coordinates.0.abs() + radius / meters_per_degree).min(90.0);
+        let meters_per_degree_lon = meters_per_degree * max_lat.to_radians().cos();
+        let degree_radius = radius / meters_per_degree_lon;
+        // Longitudes are not wrapped at ±180°, so a circle reaching past that meridian (or
+        // over a pole) is answered by the linear scan instead.
+        if center_loc.coordinates.1.abs() + degree_radius > 180.0 {
+            return self.nearby_locations_scan(center_loc, radius);
+        }
+        let squared_degree_radius = degree_radius * degree_radius;
+        let point = [center_loc.coordinates.0, center_loc.coordinates.1];
+
+        index
+            .locate_within_distance(point, squared_degree_radius)
+            .filter_map(|entry| self.locations.get(&entry.id))
+            .filter(|loc| {
+                loc.id != center_loc.id && haversine(loc.coordinates, center_loc.coordinates) <= radius
+            })
+            .collect()
+    }
+
+    /// Adds a connection whose `distance` is derived from the endpoints' haversine distance.
+    pub fn add_connection_by_coords(&mut self, from: String, to: String) {
+        let distance = match (self.locations.get(&from), self.locations.get(&to)) {
+            (Some(a), Some(b)) => haversine(a.coordinates, b.coordinates),
+            _ => return,
+        };
+        self.add_connection(from, to, distance);
+    }
+
+    /// Finds the cheapest order to visit every location in `stops`, connecting consecutive
+    /// stops with [`shortest_path`](Self::shortest_path) and summing the leg distances.
+    ///
+    /// `keep_first`/`keep_last` pin `stops`' first/last entries to those positions (useful
+    /// when the tour has a fixed origin and/or destination); every other stop is free to be
+    /// reordered. This enumerates every permutation of the free stops, so it is exponential
+    /// in their count and is only intended for a handful of waypoints.
+    pub fn optimal_tour(
+        &self,
+        stops: &[String],
+        keep_first: bool,
+        keep_last: bool,
+    ) -> Option<(Vec<String>, f64)> {
+        if stops.is_empty() {
+            return None;
+        }
+
+        let mut free: Vec<String> = stops.to_vec();
+        let first_stop = if keep_first && !free.is_empty() {
+            Some(free.remove(0))
+        } else {
+            None
+        };
+        let last_stop = if keep_last && !free.is_empty() {
+            Some(free.pop().unwrap())
+        } else {
+            None
+        };
+
+        let mut indices: Vec<usize> = (0..free.len()).collect();
+        let mut best: Option<(Vec<String>, f64)> = None;
+
+        loop {
+            let mut order: Vec<String> = Vec::with_capacity(stops.len());
+            order.extend(first_stop.clone());
+            order.extend(indices.iter().map(|&i| free[i].clone()));
+            order.extend(last_stop.clone());
+
+            if let Some((path, distance)) = self.tour_distance(&order) {
+                if best.as_ref().is_none_or(|(_, best_distance)| distance < *best_distance) {
+                    best = Some((path, distance));
+                }
+            }
+
+            if !next_permutation(&mut indices) {
+                break;
+            }
+        }
+
+        best
+    }
+
+    /// Sums `shortest_path` legs across consecutive stops, concatenating the leg paths
+    /// without repeating the shared waypoint at each join.
+    fn tour_distance(&self, order: &[String]) -> Option<(Vec<String>, f64)> {
+        if order.len() < 2 {
+            return order.first().map(|only| (vec![only.clone()], 0.0));
+        }
+
+        let mut full_path = Vec::new();
+        let mut total = 0.0;
+
+        for leg in order.windows(2) {
+            let (leg_path, leg_distance) = self.shortest_path(&leg[0], &leg[1])?;
+            if full_path.is_empty() {
+                full_path.extend(leg_path);
+            } else {
+                full_path.extend(leg_path.into_iter().skip(1));
+            }
+            total += leg_distance;
+        }
+
+        Some((full_path, total))
+    }
+}
+
+/// Advances `arr` to its next lexicographic permutation in place, returning `false` once the
+/// sequence is back at its final (descending) permutation. Runs in O(n) extra memory, so
+/// permutations can be walked one at a time without materializing all `n!` of them.
+fn next_permutation(arr: &mut [usize]) -> bool {
+    if arr.len() < 2 {
+        return false;
+    }
+
+    let mut i = arr.len() - 1;
+    while i > 0 && arr[i - 1] >= arr[i] {
+        i -= 1;
+    }
+    if i == 0 {
+        return false;
+    }
+
+    let mut j = arr.len() - 1;
+    while arr[j] <= arr[i - 1] {
+        j -= 1;
+    }
+
+    arr.swap(i - 1, j);
+    arr[i..].reverse();
+    true
+}
+
+/// Earth's mean radius in meters, used by [`haversine`].
+const EARTH_RAD: f64 = 6_371_000.0;
+
+/// Great-circle distance in meters between two `(lat, lon)` points given in degrees.
+fn haversine(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (lat_a, lon_a) = (a.0.to_radians(), a.1.to_radians());
+    let (lat_b, lon_b) = (b.0.to_radians(), b.1.to_radians());
+    let delta_lat = lat_b - lat_a;
+    let delta_lon = lon_b - lon_a;
+
+    let h = (delta_lat / 2.0).sin().powi(2)
+        + lat_a.cos() * lat_b.cos() * (delta_lon / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RAD * h.sqrt().atan2((1.0 - h).sqrt())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> NavigationGraph {
+        let mut graph = NavigationGraph::new();
+        graph.add_location(Location {
+            id: "A".to_string(),
+            name: "Central Park".to_string(),
+            coordinates: (40.7829, -73.9654),
+        });
+        graph.add_location(Location {
+            id: "B".to_string(),
+            name: "Times Square".to_string(),
+            coordinates: (40.7580, -73.9855),
+        });
+        graph.add_location(Location {
+            id: "C".to_string(),
+            name: "Empire State".to_string(),
+            coordinates: (40.7484, -73.9857),
+        });
+        graph.add_location(Location {
+            id: "D".to_string(),
+            name: "Statue of Liberty".to_string(),
+            coordinates: (40.6892, -74.0445),
+        });
+
+        graph.add_connection("A".to_string(), "B".to_string(), 1.5);
+        graph.add_connection("B".to_string(), "A".to_string(), 1.5);
+        graph.add_connection("B".to_string(), "C".to_string(), 0.8);
+        graph.add_connection("C".to_string(), "B".to_string(), 0.8);
+        graph.add_connection("C".to_string(), "D".to_string(), 3.2);
+        graph.add_connection("D".to_string(), "C".to_string(), 3.2);
+
+        graph
+    }
+
+    #[test]
+    fn indexed_nearby_locations_matches_linear_scan() {
+        let mut graph = sample_graph();
+        graph.build_spatial_index();
+        let center = graph.get_location("B").unwrap().clone();
+
+        for &radius in &[100.0, 1_000.0, 3_400.0, 5_000.0, 10_000.0] {
+            let mut scanned: Vec<&str> = graph
+                .nearby_locations_scan(&center, radius)
+                .iter()
+                .map(|loc| loc.id.as_str())
+                .collect();
+            let mut indexed: Vec<&str> = graph
+                .nearby_locations("B", radius)
+                .iter()
+                .map(|loc| loc.id.as_str())
+                .collect();
+            scanned.sort();
+            indexed.sort();
+            assert_eq!(scanned, indexed, "mismatch at radius {radius}");
+        }
+    }
+
+    #[test]
+    fn nearby_locations_indexed_includes_point_the_naive_degree_box_would_drop() {
+        // Regression for a bug where the R-tree candidate box scaled meters-to-degrees
+        // using only the latitude conversion, under-covering the east/west direction and
+        // silently dropping points a linear scan would have found.
+        let mut graph = sample_graph();
+        graph.build_spatial_index();
+
+        let nearby = graph.nearby_locations("B", 3_400.0);
+        assert!(nearby.iter().any(|loc| loc.id == "A"));
+    }
+
+    #[test]
+    fn dijkstra_finds_optimal_path_fifo_bfs_would_miss() {
+        // A FIFO queue (the old `shortest_path`) expands nodes in insertion order rather
+        // than by distance: it would enqueue the direct S->T edge before S->A, pop T first,
+        // and return the 100-unit direct route instead of the 3-unit detour through A and B.
+        let mut graph = NavigationGraph::new();
+        for id in ["S", "A", "B", "T"] {
+            graph.add_location(Location {
+                id: id.to_string(),
+                name: id.to_string(),
+                coordinates: (0.0, 0.0),
+            });
+        }
+        graph.add_connection("S".to_string(), "T".to_string(), 100.0);
+        graph.add_connection("S".to_string(), "A".to_string(), 1.0);
+        graph.add_connection("A".to_string(), "B".to_string(), 1.0);
+        graph.add_connection("B".to_string(), "T".to_string(), 1.0);
+
+        let (path, distance) = graph.shortest_path("S", "T").unwrap();
+        assert_eq!(distance, 3.0);
+        assert_eq!(path, vec!["S", "A", "B", "T"]);
+    }
+
+    #[test]
+    fn haversine_same_point_is_zero() {
+        assert_eq!(haversine((40.0, -73.0), (40.0, -73.0)), 0.0);
+    }
+
+    #[test]
+    fn haversine_quarter_great_circle() {
+        // A quarter of the globe's circumference along the equator.
+        let distance = haversine((0.0, 0.0), (0.0, 90.0));
+        let expected = std::f64::consts::PI * EARTH_RAD / 2.0;
+        assert!(
+            (distance - expected).abs() < 1.0,
+            "distance={distance} expected={expected}"
+        );
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_optimal_distance() {
+        let graph = sample_graph();
+        let (_, dijkstra_distance) = graph
+            .shortest_path_with_mode("A", "D", SearchMode::Dijkstra)
+            .unwrap();
+        let (_, astar_distance) = graph
+            .shortest_path_with_mode("A", "D", SearchMode::AStar)
+            .unwrap();
+        assert_eq!(dijkstra_distance, astar_distance);
+    }
+
+    #[test]
+    fn greedy_still_reaches_the_goal() {
+        let graph = sample_graph();
+        let (path, _) = graph
+            .shortest_path_with_mode("A", "D", SearchMode::Greedy)
+            .unwrap();
+        assert_eq!(path.first().map(String::as_str), Some("A"));
+        assert_eq!(path.last().map(String::as_str), Some("D"));
+    }
+
+    #[test]
+    fn from_csv_round_trip() {
+        let dir = std::env::temp_dir();
+        let locations_path = dir.join("navigation_system_test_locations_round_trip.csv");
+        let connections_path = dir.join("navigation_system_test_connections_round_trip.csv");
+
+        std::fs::write(&locations_path, "id,name,lat,lon\nA,Alpha,1.0,2.0\nB,Beta,3.0,4.0\n").unwrap();
+        std::fs::write(&connections_path, "from,to,distance\nA,B,5.0\n").unwrap();
+
+        let graph = NavigationGraph::from_csv(&locations_path, &connections_path).unwrap();
+        assert_eq!(graph.get_location("A").unwrap().name, "Alpha");
+        assert_eq!(graph.get_location("B").unwrap().coordinates, (3.0, 4.0));
+        assert_eq!(graph.shortest_path("A", "B").unwrap().1, 5.0);
+
+        std::fs::remove_file(&locations_path).ok();
+        std::fs::remove_file(&connections_path).ok();
+    }
+
+    #[test]
+    fn from_csv_rejects_unknown_location() {
+        let dir = std::env::temp_dir();
+        let locations_path = dir.join("navigation_system_test_locations_unknown.csv");
+        let connections_path = dir.join("navigation_system_test_connections_unknown.csv");
+
+        std::fs::write(&locations_path, "id,name,lat,lon\nA,Alpha,1.0,2.0\n").unwrap();
+        std::fs::write(&connections_path, "from,to,distance\nA,Ghost,5.0\n").unwrap();
+
+        let result = NavigationGraph::from_csv(&locations_path, &connections_path);
+        assert!(matches!(result, Err(LoadError::UnknownLocation(ref id)) if id == "Ghost"));
+
+        std::fs::remove_file(&locations_path).ok();
+        std::fs::remove_file(&connections_path).ok();
+    }
+
+    #[test]
+    fn optimal_tour_finds_cheaper_ordering() {
+        let mut graph = NavigationGraph::new();
+        for id in ["Home", "Far", "Near"] {
+            graph.add_location(Location {
+                id: id.to_string(),
+                name: id.to_string(),
+                coordinates: (0.0, 0.0),
+            });
+        }
+        graph.add_connection("Home".to_string(), "Far".to_string(), 10.0);
+        graph.add_connection("Far".to_string(), "Home".to_string(), 10.0);
+        graph.add_connection("Home".to_string(), "Near".to_string(), 1.0);
+        graph.add_connection("Near".to_string(), "Home".to_string(), 1.0);
+        graph.add_connection("Far".to_string(), "Near".to_string(), 9.0);
+        graph.add_connection("Near".to_string(), "Far".to_string(), 9.0);
+
+        let stops = vec!["Home".to_string(), "Far".to_string(), "Near".to_string()];
+        let (path, distance) = graph.optimal_tour(&stops, true, false).unwrap();
+
+        assert_eq!(distance, 10.0);
+        assert_eq!(path, vec!["Home", "Near", "Far"]);
+    }
+
+    fn range_graph() -> NavigationGraph {
+        let mut graph = NavigationGraph::new();
+        for id in ["S", "A", "B", "C", "T"] {
+            graph.add_location(Location {
+                id: id.to_string(),
+                name: id.to_string(),
+                coordinates: (0.0, 0.0),
+            });
+        }
+        graph.add_connection("S".to_string(), "T".to_string(), 10.0);
+        graph.add_connection("S".to_string(), "A".to_string(), 1.0);
+        graph.add_connection("A".to_string(), "T".to_string(), 1.0);
+        graph.add_connection("S".to_string(), "B".to_string(), 0.5);
+        graph.add_connection("B".to_string(), "C".to_string(), 0.5);
+        graph.add_connection("C".to_string(), "T".to_string(), 0.5);
+        graph
+    }
+
+    #[test]
+    fn shortest_path_within_range_ignores_overlong_edges() {
+        let graph = range_graph();
+        let (path, distance) = graph.shortest_path_within_range("S", "T", 2.0).unwrap();
+        assert_eq!(distance, 1.5);
+        assert_eq!(path, vec!["S", "B", "C", "T"]);
+    }
+
+    #[test]
+    fn shortest_path_within_range_returns_none_when_unreachable() {
+        let graph = range_graph();
+        assert!(graph.shortest_path_within_range("S", "T", 0.25).is_none());
+    }
+
+    #[test]
+    fn fewest_hops_within_range_prefers_fewer_hops_over_shorter_distance() {
+        let graph = range_graph();
+        let (path, hops) = graph.fewest_hops_within_range("S", "T", 2.0).unwrap();
+        assert_eq!(hops, 2.0);
+        assert_eq!(path, vec!["S", "A", "T"]);
+    }
+}
@@ -0,0 +1,55 @@
+use navigation_system::{Location, NavigationGraph};
+
+fn main() {
+    let mut graph = NavigationGraph::new();
+
+    let locations = vec![
+        Location {
+            id: "A".to_string(),
+            name: "Central Park".to_string(),
+            coordinates: (40.7829, -73.9654),
+        },
+        Location {
+            id: "B".to_string(),
+            name: "Times Square".to_string(),
+            coordinates: (40.7580, -73.9855),
+        },
+        Location {
+            id: "C".to_string(),
+            name: "Empire State".to_string(),
+            coordinates: (40.7484, -73.9857),
+        },
+        Location {
+            id: "D".to_string(),
+            name: "Statue of Liberty".to_string(),
+            coordinates: (40.6892, -74.0445),
+        },
+    ];
+
+    for loc in locations {
+        graph.add_location(loc);
+    }
+
+    graph.add_connection("A".to_string(), "B".to_string(), 1.5);
+    graph.add_connection("B".to_string(), "A".to_string(), 1.5);
+    graph.add_connection("B".to_string(), "C".to_string(), 0.8);
+    graph.add_connection("C".to_string(), "B".to_string(), 0.8);
+    graph.add_connection("C".to_string(), "D".to_string(), 3.2);
+    graph.add_connection("D".to_string(), "C".to_string(), 3.2);
+
+    if let Some((path, distance)) = graph.shortest_path("A", "D") {
+        println!("Path from A to D:");
+        for node in path {
+            if let Some(loc) = graph.get_location(&node) {
+                println!("- {} ({})", loc.name, loc.id);
+            }
+        }
+        println!("Total distance: {} units", distance);
+    }
+
+    let nearby = graph.nearby_locations("B", 2_000.0);
+    println!("Locations near B:");
+    for loc in nearby {
+        println!("- {} ({})", loc.name, loc.id);
+    }
+}